@@ -0,0 +1,52 @@
+use actix_cors::Cors;
+use actix_web::http::Method;
+
+const ORIGINS_VAR: &str = "FUNCTION_CORS_ALLOWED_ORIGINS";
+const METHODS_VAR: &str = "FUNCTION_CORS_ALLOWED_METHODS";
+const HEADERS_VAR: &str = "FUNCTION_CORS_ALLOWED_HEADERS";
+const MAX_AGE_VAR: &str = "FUNCTION_CORS_MAX_AGE";
+
+/// Whether CORS handling was opted into via `FUNCTION_CORS_ALLOWED_ORIGINS`.
+pub(crate) fn is_enabled() -> bool {
+    std::env::var(ORIGINS_VAR).is_ok()
+}
+
+/// Builds the CORS middleware from environment configuration. A single
+/// matching origin is always echoed back rather than blindly reflecting
+/// whatever `Origin` header was sent, so credentialed requests stay safe.
+pub(crate) fn build() -> Cors {
+    let mut cors = Cors::default();
+
+    match std::env::var(ORIGINS_VAR) {
+        Ok(origins) if origins.trim() == "*" => {
+            cors = cors.allow_any_origin().send_wildcard();
+        }
+        Ok(origins) => {
+            for origin in origins.split(',').map(str::trim).filter(|o| !o.is_empty()) {
+                cors = cors.allowed_origin(origin);
+            }
+            cors = cors.supports_credentials();
+        }
+        Err(_) => {}
+    }
+
+    if let Ok(methods) = std::env::var(METHODS_VAR) {
+        let methods: Vec<Method> = methods
+            .split(',')
+            .filter_map(|m| m.trim().parse().ok())
+            .collect();
+        cors = cors.allowed_methods(methods);
+    }
+
+    if let Ok(headers) = std::env::var(HEADERS_VAR) {
+        for header in headers.split(',').map(str::trim).filter(|h| !h.is_empty()) {
+            cors = cors.allowed_header(header);
+        }
+    }
+
+    if let Some(max_age) = std::env::var(MAX_AGE_VAR).ok().and_then(|v| v.parse::<usize>().ok()) {
+        cors = cors.max_age(max_age);
+    }
+
+    cors
+}
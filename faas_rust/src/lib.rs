@@ -0,0 +1,86 @@
+mod cors;
+pub mod payload;
+pub mod request_reader;
+pub mod response_writer;
+mod tls;
+
+use actix_web::http::KeepAlive;
+use actix_web::middleware::Condition;
+use actix_web::{web, App, HttpServer, Resource};
+use std::time::Duration;
+
+const DEFAULT_PORT: u16 = 8080;
+const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 30;
+
+/// Starts the actix-web server that drives the generated `handle_event` function.
+///
+/// `configure` receives the single `Resource` the runtime exposes and should
+/// attach the handler to it, e.g. `|r| r.to(handle_event)`. TLS is used
+/// instead of plain HTTP when `FUNCTION_TLS_CERT_PATH`/`FUNCTION_TLS_KEY_PATH`
+/// are set and the runtime was built with the `ssl` or `rust-tls` feature.
+///
+/// On SIGTERM (e.g. a scale-to-zero platform stopping the pod) actix stops
+/// accepting new connections and waits up to `FUNCTION_SHUTDOWN_TIMEOUT_SECS`
+/// for in-flight `handle_event` calls to finish before exiting.
+pub async fn start_runtime<F>(configure: F) -> std::io::Result<()>
+where
+    F: Fn(Resource) -> Resource + Clone + Send + 'static,
+{
+    let port = std::env::var("FUNCTION_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DEFAULT_PORT);
+
+    let mut server = HttpServer::new(move || {
+        let configure = configure.clone();
+        App::new()
+            .wrap(Condition::new(cors::is_enabled(), cors::build()))
+            .service(configure(web::resource("/")))
+    })
+    .shutdown_timeout(shutdown_timeout_secs())
+    .keep_alive(keep_alive());
+
+    if let Some(timeout) = request_timeout() {
+        server = server.client_request_timeout(timeout);
+    }
+
+    #[cfg(feature = "ssl")]
+    {
+        if let Some((cert_path, key_path)) = tls::cert_and_key_paths() {
+            let acceptor = tls::build_openssl_acceptor(&cert_path, &key_path)?;
+            return server.bind_openssl(("0.0.0.0", port), acceptor)?.run().await;
+        }
+    }
+
+    #[cfg(feature = "rust-tls")]
+    {
+        if let Some((cert_path, key_path)) = tls::cert_and_key_paths() {
+            let config = tls::build_rustls_config(&cert_path, &key_path)?;
+            return server.bind_rustls(("0.0.0.0", port), config)?.run().await;
+        }
+    }
+
+    server.bind(("0.0.0.0", port))?.run().await
+}
+
+fn shutdown_timeout_secs() -> u64 {
+    std::env::var("FUNCTION_SHUTDOWN_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_SECS)
+}
+
+fn keep_alive() -> KeepAlive {
+    std::env::var("FUNCTION_KEEP_ALIVE_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(|secs| KeepAlive::Timeout(Duration::from_secs(secs)))
+        .unwrap_or_default()
+}
+
+fn request_timeout() -> Option<Duration> {
+    std::env::var("FUNCTION_REQUEST_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(Duration::from_secs)
+}
@@ -0,0 +1,128 @@
+use cloudevent::{Event, Payload, SpecVersion};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+const OUTPUT_SOURCE: &str = "faas-rust";
+const OUTPUT_EVENT_TYPE: &str = "faas-rust.output";
+
+/// Deserializes the JSON payload of the given event into `T`, for
+/// `#[faas_function]`-generated handlers that take a plain domain type
+/// instead of `cloudevent::Event`.
+pub fn extract<T: DeserializeOwned>(
+    event: Option<Event>,
+    position: usize,
+) -> Result<T, actix_web::Error> {
+    let event = event.ok_or_else(|| {
+        actix_web::error::ErrorBadRequest(format!("Expecting event in position {}", position))
+    })?;
+    let data: &[u8] = event
+        .payload
+        .as_ref()
+        .map(|p| p.data.as_slice())
+        .unwrap_or(&[]);
+
+    serde_json::from_slice(data).map_err(|e| {
+        actix_web::error::ErrorBadRequest(format!(
+            "Failed to parse payload in position {}: {}",
+            position, e
+        ))
+    })
+}
+
+/// Wraps a `Serialize` handler return value into a single event whose
+/// payload is its JSON representation. Each call gets a fresh UUID v4 id,
+/// since CloudEvents ids only need to be unique per source and a
+/// process-local counter would collide across replicas and restarts.
+pub fn wrap<T: Serialize>(output: &T) -> Result<Event, actix_web::Error> {
+    let data = serde_json::to_vec(output).map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(Event {
+        id: uuid::Uuid::new_v4().to_string(),
+        spec_version: SpecVersion::V1_0,
+        source: OUTPUT_SOURCE.to_string(),
+        event_type: OUTPUT_EVENT_TYPE.to_string(),
+        subject: None,
+        time: None,
+        payload: Some(Payload {
+            content_type: "application/json".to_string(),
+            data,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Greeting {
+        message: String,
+    }
+
+    fn event_with_json(json: &str) -> Event {
+        Event {
+            id: "1".to_string(),
+            spec_version: SpecVersion::V1_0,
+            source: "test".to_string(),
+            event_type: "test.event".to_string(),
+            subject: None,
+            time: None,
+            payload: Some(Payload {
+                content_type: "application/json".to_string(),
+                data: json.as_bytes().to_vec(),
+            }),
+        }
+    }
+
+    #[test]
+    fn extract_deserializes_the_event_payload() {
+        let event = event_with_json(r#"{"message":"hi"}"#);
+        let greeting: Greeting = extract(Some(event), 1).unwrap();
+        assert_eq!(
+            greeting,
+            Greeting {
+                message: "hi".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn extract_errors_when_no_event_is_present() {
+        let err = extract::<Greeting>(None, 1).unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            actix_web::http::StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[test]
+    fn extract_errors_on_invalid_json() {
+        let event = event_with_json("not json");
+        let err = extract::<Greeting>(Some(event), 1).unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            actix_web::http::StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[test]
+    fn wrap_serializes_the_output_as_json_payload() {
+        let greeting = Greeting {
+            message: "hi".to_string(),
+        };
+        let event = wrap(&greeting).unwrap();
+
+        let payload = event.payload.unwrap();
+        assert_eq!(payload.content_type, "application/json");
+        let round_tripped: Greeting = serde_json::from_slice(&payload.data).unwrap();
+        assert_eq!(round_tripped, greeting);
+    }
+
+    #[test]
+    fn wrap_generates_a_unique_id_per_call() {
+        let a = wrap(&1u8).unwrap();
+        let b = wrap(&1u8).unwrap();
+        assert_ne!(a.id, b.id);
+    }
+}
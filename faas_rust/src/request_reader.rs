@@ -0,0 +1,152 @@
+use actix_web::http::header::CONTENT_TYPE;
+use actix_web::web::Bytes;
+use actix_web::HttpRequest;
+use cloudevent::http::*;
+use cloudevent::{Event, Payload};
+
+const BATCH_CONTENT_TYPE: &str = "application/cloudevents-batch+json";
+const STRUCTURED_CONTENT_TYPE: &str = "application/cloudevents+json";
+
+/// Reads a CloudEvents request off the wire, returning the encoding the
+/// caller used together with the events it carried. `None` means the
+/// request carried no event at all (e.g. a health check).
+pub async fn read_cloud_event(
+    req: HttpRequest,
+    body: Bytes,
+) -> Result<Option<(Encoding, Vec<Event>)>, actix_web::Error> {
+    if body.is_empty() && req.headers().get(CE_ID_HEADER).is_none() {
+        return Ok(None);
+    }
+
+    match classify_content_type(content_type(&req).as_deref()) {
+        ContentKind::Batch => {
+            let events: Vec<Event> = serde_json::from_slice(&body)
+                .map_err(|e| actix_web::error::ErrorBadRequest(e))?;
+            Ok(Some((Encoding::STRUCTURED, events)))
+        }
+        ContentKind::Structured => {
+            let event: Event = serde_json::from_slice(&body)
+                .map_err(|e| actix_web::error::ErrorBadRequest(e))?;
+            Ok(Some((Encoding::STRUCTURED, vec![event])))
+        }
+        ContentKind::Binary => {
+            let event = read_binary(&req, body)?;
+            Ok(Some((Encoding::BINARY, vec![event])))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ContentKind {
+    Batch,
+    Structured,
+    Binary,
+}
+
+/// Classifies a `Content-Type` header by its media type (the part before
+/// any `;`-separated parameters), matching the CloudEvents spec's allowance
+/// for senders to append parameters such as `; charset=utf-8`.
+fn classify_content_type(content_type: Option<&str>) -> ContentKind {
+    let essence = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(|s| s.trim().to_ascii_lowercase());
+
+    match essence.as_deref() {
+        Some(BATCH_CONTENT_TYPE) => ContentKind::Batch,
+        Some(STRUCTURED_CONTENT_TYPE) => ContentKind::Structured,
+        _ => ContentKind::Binary,
+    }
+}
+
+fn content_type(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+}
+
+fn read_binary(req: &HttpRequest, body: Bytes) -> Result<Event, actix_web::Error> {
+    let header = |name: &str| -> Result<String, actix_web::Error> {
+        req.headers()
+            .get(name)
+            .ok_or_else(|| actix_web::error::ErrorBadRequest(format!("Missing header {}", name)))?
+            .to_str()
+            .map(|s| s.to_string())
+            .map_err(|e| actix_web::error::ErrorBadRequest(e))
+    };
+
+    let id = header(CE_ID_HEADER)?;
+    let spec_version = header(CE_SPECVERSION_HEADER)?
+        .parse()
+        .map_err(|e| actix_web::error::ErrorBadRequest(format!("Invalid spec version: {}", e)))?;
+    let source = header(CE_SOURCE_HEADER)?;
+    let event_type = header(CE_TYPE_HEADER)?;
+    let subject = req
+        .headers()
+        .get(CE_SUBJECT_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string());
+    let time = req
+        .headers()
+        .get(CE_TIME_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| chrono::DateTime::parse_from_rfc3339(v))
+        .transpose()
+        .map_err(|e| actix_web::error::ErrorBadRequest(format!("Invalid time: {}", e)))?
+        .map(|t| t.with_timezone(&chrono::Utc));
+
+    let payload = if body.is_empty() {
+        None
+    } else {
+        let content_type = content_type(req).unwrap_or_else(|| "application/octet-stream".into());
+        Some(Payload {
+            content_type,
+            data: body.to_vec(),
+        })
+    };
+
+    Ok(Event {
+        id,
+        spec_version,
+        source,
+        event_type,
+        subject,
+        time,
+        payload,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_content_type_is_recognized_with_parameters() {
+        assert_eq!(
+            classify_content_type(Some("application/cloudevents-batch+json; charset=utf-8")),
+            ContentKind::Batch
+        );
+    }
+
+    #[test]
+    fn structured_content_type_is_recognized_with_parameters() {
+        assert_eq!(
+            classify_content_type(Some("application/cloudevents+json;charset=utf-8")),
+            ContentKind::Structured
+        );
+    }
+
+    #[test]
+    fn structured_content_type_is_case_insensitive() {
+        assert_eq!(
+            classify_content_type(Some("Application/CloudEvents+JSON")),
+            ContentKind::Structured
+        );
+    }
+
+    #[test]
+    fn missing_or_unrecognized_content_type_falls_back_to_binary() {
+        assert_eq!(classify_content_type(None), ContentKind::Binary);
+        assert_eq!(classify_content_type(Some("text/plain")), ContentKind::Binary);
+    }
+}
@@ -18,7 +18,10 @@ pub fn write_cloud_event(
     } else if ce.len() == 0 {
         return Ok(HttpResponse::Accepted().finish());
     } else {
-        unimplemented!()
+        // Binary encoding has no batch representation, so regardless of
+        // what was requested, multiple events always go out as a
+        // CloudEvents "batch" structured array.
+        write_batch(ce)
     }
 }
 
@@ -53,3 +56,56 @@ fn write_structured(event: Event) -> Result<HttpResponse, actix_web::Error> {
         })
         .map_err(|e| actix_web::error::ErrorInternalServerError(e))
 }
+
+fn write_batch(events: Vec<Event>) -> Result<HttpResponse, actix_web::Error> {
+    serde_json::to_vec(&events)
+        .map(|j| {
+            HttpResponse::Ok()
+                .content_type("application/cloudevents-batch+json")
+                .body(j)
+        })
+        .map_err(|e| actix_web::error::ErrorInternalServerError(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::to_bytes;
+    use actix_web::http::header::CONTENT_TYPE;
+    use cloudevent::SpecVersion;
+
+    fn sample_event(id: &str) -> Event {
+        Event {
+            id: id.to_string(),
+            spec_version: SpecVersion::V1_0,
+            source: "test".to_string(),
+            event_type: "test.event".to_string(),
+            subject: None,
+            time: None,
+            payload: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn empty_event_list_is_accepted() {
+        let response = write_cloud_event(vec![], None).unwrap();
+        assert_eq!(response.status(), actix_web::http::StatusCode::ACCEPTED);
+    }
+
+    #[actix_rt::test]
+    async fn multiple_events_round_trip_as_a_structured_batch() {
+        let events = vec![sample_event("1"), sample_event("2")];
+        let response = write_cloud_event(events, Some(Encoding::BINARY)).unwrap();
+
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/cloudevents-batch+json"
+        );
+
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let parsed: Vec<Event> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].id, "1");
+        assert_eq!(parsed[1].id, "2");
+    }
+}
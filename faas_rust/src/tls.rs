@@ -0,0 +1,65 @@
+#[cfg(feature = "ssl")]
+use openssl::ssl::{SslAcceptor, SslAcceptorBuilder, SslFiletype, SslMethod};
+#[cfg(feature = "rust-tls")]
+use rustls::{Certificate, PrivateKey, ServerConfig};
+#[cfg(feature = "rust-tls")]
+use std::fs::File;
+#[cfg(feature = "rust-tls")]
+use std::io::BufReader;
+
+const CERT_PATH_VAR: &str = "FUNCTION_TLS_CERT_PATH";
+const KEY_PATH_VAR: &str = "FUNCTION_TLS_KEY_PATH";
+
+/// Returns the configured certificate/private-key paths, if both are set.
+/// Absence means the runtime should fall back to plain HTTP.
+pub(crate) fn cert_and_key_paths() -> Option<(String, String)> {
+    let cert = std::env::var(CERT_PATH_VAR).ok()?;
+    let key = std::env::var(KEY_PATH_VAR).ok()?;
+    Some((cert, key))
+}
+
+#[cfg(feature = "ssl")]
+pub(crate) fn build_openssl_acceptor(
+    cert_path: &str,
+    key_path: &str,
+) -> std::io::Result<SslAcceptorBuilder> {
+    let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    builder
+        .set_private_key_file(key_path, SslFiletype::PEM)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    builder
+        .set_certificate_chain_file(cert_path)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    Ok(builder)
+}
+
+#[cfg(feature = "rust-tls")]
+pub(crate) fn build_rustls_config(cert_path: &str, key_path: &str) -> std::io::Result<ServerConfig> {
+    let mut cert_reader = BufReader::new(File::open(cert_path)?);
+    let mut key_reader = BufReader::new(File::open(key_path)?);
+
+    let cert_chain = rustls_pemfile::certs(&mut cert_reader)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid certificate"))?
+        .into_iter()
+        .map(Certificate)
+        .collect();
+    let mut keys: Vec<PrivateKey> = rustls_pemfile::pkcs8_private_keys(&mut key_reader)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid private key"))?
+        .into_iter()
+        .map(PrivateKey)
+        .collect();
+
+    if keys.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "No private key found",
+        ));
+    }
+
+    ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(cert_chain, keys.remove(0))
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
+}
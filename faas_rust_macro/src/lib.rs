@@ -43,24 +43,29 @@ fn generate_handler(function_ast: syn::ItemFn) -> TokenStream {
         .enumerate()
         .map(|(i, arg)|
             extract_type_from_fn_arg(arg)
-                .and_then(|ty| {
+                .map(|ty| {
                     let varname = format_ident!("_arg{}", i);
+                    let num = i + 1;
                     if is_event(ty) {
-                        let num = i + 1;
-                        Some((varname.clone(), quote_spanned! {arg.span()=>
+                        (varname.clone(), quote_spanned! {arg.span()=>
                             let #varname: cloudevent::Event = events.pop().ok_or(actix_web::error::ErrorBadRequest(format!("Expecting event in position {}", #num)))?;
-                        }))
+                        })
                     } else if is_option_event(ty) {
-                        Some((varname.clone(), quote_spanned! {arg.span()=>
+                        (varname.clone(), quote_spanned! {arg.span()=>
                             let #varname: Option<cloudevent::Event> = events.pop();
-                        }))
+                        })
                     } else {
-                        None
+                        // Any other `DeserializeOwned` type is treated as the JSON payload
+                        // of the next event, so plain domain functions don't need to know
+                        // about `cloudevent::Event` at all.
+                        (varname.clone(), quote_spanned! {arg.span()=>
+                            let #varname: #ty = faas_rust::payload::extract(events.pop(), #num)?;
+                        })
                     }
                 })
                 .unwrap_or((
                     format_ident!("{}", "err"),
-                    syn::Error::new_spanned(arg, "Type should be Event or Option<Event>").to_compile_error()
+                    syn::Error::new_spanned(arg, "Unable to determine argument type").to_compile_error()
                 ))
 
         )
@@ -86,7 +91,7 @@ fn generate_handler(function_ast: syn::ItemFn) -> TokenStream {
     let output_mapper: TokenStream = map_output(&function_ast.sig.output).unwrap_or(
         syn::Error::new_spanned(
             function_ast.sig,
-            "Return type should be Result<V, E>, where V is Vec<Event> or Option<Event> or Event",
+            "Return type should be Result<V, E>, where V is Vec<Event>, Option<Event>, Event, or a Serialize type",
         )
         .to_compile_error(),
     );
@@ -139,7 +144,11 @@ fn map_output(rt: &ReturnType) -> Option<TokenStream> {
         vec![output]
         })
     } else {
-        None
+        // Any other `Serialize` type is wrapped into a single event carrying
+        // the JSON-serialized struct as its payload.
+        Some(quote! {
+        vec![faas_rust::payload::wrap(&output)?]
+        })
     }
 }
 